@@ -4,10 +4,19 @@
 
 /// The type of attitude for orientation, torque and wrench.
 pub type Attitude<T> = (T, [T; 3]);
+/// A linear position paired with an orientation.
+pub type Pose<T> = (Vector3<T>, Attitude<T>);
 /// Reexport 3D vector from vecmath.
 pub use vecmath::Vector3;
 
+pub use mass::MassDistribution;
+pub use motion::{ConstantVelocityMotion, RigidMotion};
+
 use vecmath::traits::Float;
+use vecmath::Matrix3;
+
+mod mass;
+mod motion;
 
 /// A minimalistic description of a rigid body.
 #[derive(Clone, Copy, Debug)]
@@ -24,26 +33,46 @@ pub struct RigidBody<T> {
     pub tor: Attitude<T>,
     /// Wrench (angular acceleration).
     pub wre: Attitude<T>,
+    /// Inverse of the total mass, `0` for an infinite-mass (immovable) body.
+    pub inv_mass: T,
+    /// Inverse of the inertia tensor about the center of mass, in body-local
+    /// coordinates.
+    pub inv_inertia: Matrix3<T>,
+    /// Linear force accumulated since the last `integrate_forces`.
+    pub force_accum: Vector3<T>,
+    /// Torque accumulated since the last `integrate_forces`.
+    pub torque_accum: Vector3<T>,
+    /// Per-axis factor applied to linear velocity/acceleration contributions
+    /// in `update_linear`. `[1, 1, 1]` leaves the body unconstrained; setting
+    /// a component to `0` pins translation along that axis.
+    pub lin_factor: Vector3<T>,
+    /// Per-axis factor applied to the angular velocity axis in
+    /// `update_angular`. `[1, 1, 1]` leaves the body unconstrained; setting a
+    /// component to `0` pins rotation about that axis.
+    pub ang_factor: Vector3<T>,
 }
 
 impl<T: Float> RigidBody<T> {
     /// Updates linear coordinates by moving through time.
     pub fn update_linear(&mut self, dt: T) {
         use vecmath::vec3_add as add;
+        use vecmath::vec3_mul as mul;
         use vecmath::vec3_scale as scale;
 
         let half_dt = T::from_f64(0.5) * dt;
-        self.vel = add(self.vel, scale(self.acc, half_dt));
+        self.vel = mul(add(self.vel, scale(self.acc, half_dt)), self.lin_factor);
         self.pos = add(self.pos, scale(self.vel, dt));
-        self.vel = add(self.vel, scale(self.acc, half_dt));
+        self.vel = mul(add(self.vel, scale(self.acc, half_dt)), self.lin_factor);
     }
 
     /// Updates angular coordinates by moving through time.
     pub fn update_angular(&mut self, dt: T) {
         let half_dt = T::from_f64(0.5) * dt;
         self.tor = angular(self.tor, self.wre, half_dt);
+        self.tor = mask_angular_velocity(self.tor, self.ang_factor);
         self.ori = angular(self.ori, self.tor, dt);
         self.tor = angular(self.tor, self.wre, half_dt);
+        self.tor = mask_angular_velocity(self.tor, self.ang_factor);
     }
 
     /// Update coordinates by moving it through time.
@@ -51,6 +80,96 @@ impl<T: Float> RigidBody<T> {
         self.update_linear(dt);
         self.update_angular(dt);
     }
+
+    /// Accumulates a linear force, to be applied on the next `integrate_forces`.
+    pub fn apply_force(&mut self, f: Vector3<T>) {
+        use vecmath::vec3_add as add;
+
+        self.force_accum = add(self.force_accum, f);
+    }
+
+    /// Accumulates a force applied at a world-space point, contributing both
+    /// to the linear force and, through `r × f` (with `r = point - pos`), to
+    /// the torque.
+    pub fn apply_force_at_point(&mut self, f: Vector3<T>, point: Vector3<T>) {
+        use vecmath::vec3_add as add;
+        use vecmath::vec3_cross as cross;
+        use vecmath::vec3_sub as sub;
+
+        let r = sub(point, self.pos);
+        self.force_accum = add(self.force_accum, f);
+        self.torque_accum = add(self.torque_accum, cross(r, f));
+    }
+
+    /// Accumulates a torque, to be applied on the next `integrate_forces`.
+    pub fn apply_torque(&mut self, t: Vector3<T>) {
+        use vecmath::vec3_add as add;
+
+        self.torque_accum = add(self.torque_accum, t);
+    }
+
+    /// Turns the accumulated force and torque into `acc` and `wre`, via
+    /// `inv_mass` and `inv_inertia`, then zeroes the accumulators. Call this
+    /// before `update` to drive the body from forces instead of setting
+    /// `acc`/`wre` by hand.
+    pub fn integrate_forces(&mut self, _dt: T) {
+        use vecmath::vec3_scale as scale;
+
+        self.acc = scale(self.force_accum, self.inv_mass);
+        self.wre = vector3_to_attitude(mass::mat3_transform(self.inv_inertia, self.torque_accum));
+        self.force_accum = [T::zero(); 3];
+        self.torque_accum = [T::zero(); 3];
+    }
+
+    /// Creates a body at `start` with the linear and angular velocities
+    /// needed to reach `end` in exactly `dt` time. Mass properties and the
+    /// force/torque accumulators are left at zero.
+    pub fn between(start: Pose<T>, end: Pose<T>, dt: T) -> RigidBody<T> {
+        use vecmath::vec3_scale as scale;
+        use vecmath::vec3_sub as sub;
+
+        let vel = scale(sub(end.0, start.0), T::one() / dt);
+        let rel = relative_attitude(start.1, end.1);
+        let tor = (rel.0 / dt, rel.1);
+
+        RigidBody {
+            pos: start.0,
+            vel,
+            acc: [T::zero(); 3],
+            ori: start.1,
+            tor,
+            wre: (T::zero(), [T::zero(); 3]),
+            inv_mass: T::zero(),
+            inv_inertia: [[T::zero(); 3]; 3],
+            force_accum: [T::zero(); 3],
+            torque_accum: [T::zero(); 3],
+            lin_factor: [T::one(); 3],
+            ang_factor: [T::one(); 3],
+        }
+    }
+}
+
+/// Masks an angular velocity's per-axis components by `factor`, preserving
+/// the `(magnitude, axis)` invariant instead of scaling the axis in place.
+fn mask_angular_velocity<T: Float>(tor: Attitude<T>, factor: Vector3<T>) -> Attitude<T> {
+    use vecmath::vec3_mul as mul;
+    use vecmath::vec3_scale as scale;
+
+    vector3_to_attitude(mul(scale(tor.1, tor.0), factor))
+}
+
+/// Converts a vector into its `(angle, axis)` attitude representation, using
+/// its length as the angle and its normalized direction as the axis.
+fn vector3_to_attitude<T: Float>(v: Vector3<T>) -> Attitude<T> {
+    use vecmath::vec3_len as len;
+    use vecmath::vec3_normalized as normalized;
+
+    let l = len(v);
+    if l > T::zero() {
+        (l, normalized(v))
+    } else {
+        (T::zero(), [T::zero(); 3])
+    }
 }
 
 /// Solves the analogue of `s' = s + v * t` for attitude.
@@ -68,3 +187,52 @@ pub fn angular<T: Float>(a: Attitude<T>, b: Attitude<T>, t: T) -> Attitude<T> {
                scale(b.1, dot(b.1, a.1) * (T::one() - cos))));
     (angle, axis)
 }
+
+/// Returns the attitude that rotates `start` into `end`, via quaternions.
+fn relative_attitude<T: Float>(start: Attitude<T>, end: Attitude<T>) -> Attitude<T> {
+    let qs = attitude_to_quaternion(start);
+    let qe = attitude_to_quaternion(end);
+    quaternion_to_attitude(quaternion_mul(qe, quaternion_conjugate(qs)))
+}
+
+/// A quaternion as `(w, xyz)`.
+type Quaternion<T> = (T, Vector3<T>);
+
+fn attitude_to_quaternion<T: Float>(a: Attitude<T>) -> Quaternion<T> {
+    use vecmath::vec3_scale as scale;
+
+    let half = a.0 * T::from_f64(0.5);
+    (half.cos(), scale(a.1, half.sin()))
+}
+
+fn quaternion_to_attitude<T: Float>(q: Quaternion<T>) -> Attitude<T> {
+    use vecmath::vec3_len as len;
+    use vecmath::vec3_normalized as normalized;
+
+    let (w, v) = q;
+    let s = len(v);
+    if s > T::zero() {
+        (T::from_f64(2.0) * s.atan2(w), normalized(v))
+    } else {
+        (T::zero(), [T::zero(); 3])
+    }
+}
+
+fn quaternion_mul<T: Float>(a: Quaternion<T>, b: Quaternion<T>) -> Quaternion<T> {
+    use vecmath::vec3_add as add;
+    use vecmath::vec3_cross as cross;
+    use vecmath::vec3_dot as dot;
+    use vecmath::vec3_scale as scale;
+
+    let (aw, av) = a;
+    let (bw, bv) = b;
+    let w = aw * bw - dot(av, bv);
+    let v = add(add(scale(bv, aw), scale(av, bw)), cross(av, bv));
+    (w, v)
+}
+
+fn quaternion_conjugate<T: Float>(q: Quaternion<T>) -> Quaternion<T> {
+    use vecmath::vec3_neg as neg;
+
+    (q.0, neg(q.1))
+}
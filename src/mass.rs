@@ -0,0 +1,158 @@
+//! Mass aggregation for rigid bodies.
+
+use vecmath::traits::Float;
+use vecmath::{Matrix3, Vector3};
+
+/// Accumulates point masses in body-local coordinates and derives the
+/// aggregate mass properties a rigid body needs to respond to forces and
+/// torques: total mass, center of mass and the inertia tensor about it.
+///
+/// Masses are recomputed lazily, so `add_mass` stays cheap during setup;
+/// call `finalize` explicitly if you need the derived quantities without
+/// reading one of the accessors first.
+#[derive(Clone, Debug)]
+pub struct MassDistribution<T> {
+    masses: Vec<(T, Vector3<T>)>,
+    total_mass: T,
+    center_of_mass: Vector3<T>,
+    inertia: Matrix3<T>,
+    inverse_inertia: Matrix3<T>,
+    dirty: bool,
+}
+
+impl<T: Float> MassDistribution<T> {
+    /// Creates an empty mass distribution.
+    pub fn new() -> MassDistribution<T> {
+        MassDistribution {
+            masses: vec![],
+            total_mass: T::zero(),
+            center_of_mass: [T::zero(); 3],
+            inertia: [[T::zero(); 3]; 3],
+            inverse_inertia: [[T::zero(); 3]; 3],
+            dirty: true,
+        }
+    }
+
+    /// Adds a point mass `m` at body-local position `pos`.
+    pub fn add_mass(&mut self, m: T, pos: Vector3<T>) {
+        self.masses.push((m, pos));
+        self.dirty = true;
+    }
+
+    /// Recomputes total mass, center of mass and the inertia tensor
+    /// (plus its inverse) from the accumulated point masses.
+    pub fn finalize(&mut self) {
+        use vecmath::vec3_add as add;
+        use vecmath::vec3_scale as scale;
+        use vecmath::vec3_sub as sub;
+
+        let mut total_mass = T::zero();
+        let mut moment = [T::zero(); 3];
+        for &(m, r) in &self.masses {
+            total_mass += m;
+            moment = add(moment, scale(r, m));
+        }
+        self.total_mass = total_mass;
+        self.center_of_mass = if total_mass > T::zero() {
+            scale(moment, T::one() / total_mass)
+        } else {
+            [T::zero(); 3]
+        };
+
+        let mut inertia = [[T::zero(); 3]; 3];
+        for &(m, r) in &self.masses {
+            let d = sub(r, self.center_of_mass);
+            let sq_len = d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+            for i in 0..3 {
+                for j in 0..3 {
+                    let delta = if i == j { T::one() } else { T::zero() };
+                    inertia[i][j] += m * (sq_len * delta - d[i] * d[j]);
+                }
+            }
+        }
+        self.inertia = inertia;
+        self.inverse_inertia = mat3_inverse(inertia);
+        self.dirty = false;
+    }
+
+    /// Returns the total accumulated mass, recomputing if necessary.
+    pub fn mass(&mut self) -> T {
+        if self.dirty {
+            self.finalize();
+        }
+        self.total_mass
+    }
+
+    /// Returns the center of mass in body-local coordinates, recomputing if necessary.
+    pub fn center_of_mass(&mut self) -> Vector3<T> {
+        if self.dirty {
+            self.finalize();
+        }
+        self.center_of_mass
+    }
+
+    /// Returns the inertia tensor about the center of mass, recomputing if necessary.
+    pub fn inertia_tensor(&mut self) -> Matrix3<T> {
+        if self.dirty {
+            self.finalize();
+        }
+        self.inertia
+    }
+
+    /// Returns the inverse of the inertia tensor, recomputing if necessary.
+    ///
+    /// This is what converts an accumulated torque into angular acceleration.
+    pub fn inverse_inertia_tensor(&mut self) -> Matrix3<T> {
+        if self.dirty {
+            self.finalize();
+        }
+        self.inverse_inertia
+    }
+}
+
+impl<T: Float> Default for MassDistribution<T> {
+    fn default() -> Self {
+        MassDistribution::new()
+    }
+}
+
+/// Computes the inverse of a 3x3 matrix via the adjugate method.
+///
+/// Returns the zero matrix for a singular input (e.g. an empty
+/// `MassDistribution`), since there is no meaningful inverse inertia to fall
+/// back on.
+pub(crate) fn mat3_inverse<T: Float>(m: Matrix3<T>) -> Matrix3<T> {
+    let cofactor00 = m[1][1] * m[2][2] - m[1][2] * m[2][1];
+    let cofactor01 = m[1][2] * m[2][0] - m[1][0] * m[2][2];
+    let cofactor02 = m[1][0] * m[2][1] - m[1][1] * m[2][0];
+    let det = m[0][0] * cofactor00 + m[0][1] * cofactor01 + m[0][2] * cofactor02;
+    if det == T::zero() {
+        return [[T::zero(); 3]; 3];
+    }
+    let inv_det = T::one() / det;
+
+    let cofactor10 = m[0][2] * m[2][1] - m[0][1] * m[2][2];
+    let cofactor11 = m[0][0] * m[2][2] - m[0][2] * m[2][0];
+    let cofactor12 = m[0][1] * m[2][0] - m[0][0] * m[2][1];
+
+    let cofactor20 = m[0][1] * m[1][2] - m[0][2] * m[1][1];
+    let cofactor21 = m[0][2] * m[1][0] - m[0][0] * m[1][2];
+    let cofactor22 = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+
+    // Adjugate is the transpose of the cofactor matrix.
+    [
+        [cofactor00 * inv_det, cofactor10 * inv_det, cofactor20 * inv_det],
+        [cofactor01 * inv_det, cofactor11 * inv_det, cofactor21 * inv_det],
+        [cofactor02 * inv_det, cofactor12 * inv_det, cofactor22 * inv_det],
+    ]
+}
+
+/// Transforms a vector by a 3x3 matrix, e.g. converting a torque into an
+/// angular acceleration via an inverse inertia tensor.
+pub(crate) fn mat3_transform<T: Float>(m: Matrix3<T>, v: Vector3<T>) -> Vector3<T> {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
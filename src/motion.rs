@@ -0,0 +1,112 @@
+//! Continuous pose curves sampled between discrete integration steps.
+
+use vecmath::traits::Float;
+
+use crate::{angular, Attitude, Pose, RigidBody, Vector3};
+
+/// A one-parameter curve giving a body's pose at arbitrary time `t`, without
+/// mutating the body. This is the prerequisite for nonlinear time-of-impact
+/// and swept-collision queries between discrete `update` steps.
+pub trait RigidMotion<T> {
+    /// Returns the position and orientation at time `t`, relative to `t = 0`.
+    fn position_at(&self, t: T) -> Pose<T>;
+}
+
+/// A rigid motion that moves with the constant linear and angular velocity
+/// a `RigidBody` has at `t = 0`, optionally rotating about a local pivot
+/// point rather than the body's own origin.
+#[derive(Clone, Copy, Debug)]
+pub struct ConstantVelocityMotion<T> {
+    /// Linear position at `t = 0`.
+    pub pos: Vector3<T>,
+    /// Linear velocity.
+    pub vel: Vector3<T>,
+    /// Orientation at `t = 0`.
+    pub ori: Attitude<T>,
+    /// Angular velocity.
+    pub tor: Attitude<T>,
+    /// Local point to rotate about, in body-local coordinates.
+    pub pivot: Vector3<T>,
+}
+
+impl<T: Float> ConstantVelocityMotion<T> {
+    /// Creates a constant-velocity motion from a rigid body's current state,
+    /// rotating about the body's own origin.
+    pub fn new(body: &RigidBody<T>) -> ConstantVelocityMotion<T> {
+        ConstantVelocityMotion {
+            pos: body.pos,
+            vel: body.vel,
+            ori: body.ori,
+            tor: body.tor,
+            pivot: [T::zero(); 3],
+        }
+    }
+
+    /// Sets the local pivot point to rotate about.
+    pub fn with_pivot(mut self, pivot: Vector3<T>) -> ConstantVelocityMotion<T> {
+        self.pivot = pivot;
+        self
+    }
+}
+
+impl<T: Float> RigidMotion<T> for ConstantVelocityMotion<T> {
+    fn position_at(&self, t: T) -> Pose<T> {
+        use vecmath::vec3_add as add;
+        use vecmath::vec3_neg as neg;
+        use vecmath::vec3_scale as scale;
+        use vecmath::vec3_sub as sub;
+
+        let ori = angular(self.ori, self.tor, t);
+
+        // Translation and pivot-rotation are independent: `pos + vel*t` moves
+        // the body along its straight-line path, and rotating about a pivot
+        // other than the body's own origin only adds a correction equal to
+        // how far the constant body-local offset `-pivot` has swung by `t`.
+        let offset = neg(self.pivot);
+        let correction = sub(rotate(self.tor, t, offset), offset);
+        let pos = add(add(self.pos, scale(self.vel, t)), correction);
+
+        (pos, ori)
+    }
+}
+
+/// Rotates `v` about `tor`'s axis by the angle `tor.0 * t`, using the same
+/// Rodrigues' rotation formula as `angular`.
+fn rotate<T: Float>(tor: Attitude<T>, t: T, v: Vector3<T>) -> Vector3<T> {
+    use vecmath::vec3_add as add;
+    use vecmath::vec3_cross as cross;
+    use vecmath::vec3_dot as dot;
+    use vecmath::vec3_scale as scale;
+
+    let angle = tor.0 * t;
+    let axis = tor.1;
+    let cos = angle.cos();
+    let sin = angle.sin();
+    add(
+        scale(v, cos),
+        add(
+            scale(cross(axis, v), sin),
+            scale(axis, dot(axis, v) * (T::one() - cos)),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_pivot_reduces_to_pure_translation() {
+        let motion = ConstantVelocityMotion {
+            pos: [0.0, 0.0, 0.0],
+            vel: [1.0, 0.0, 0.0],
+            ori: (0.0, [0.0, 0.0, 1.0]),
+            tor: (::std::f64::consts::FRAC_PI_2, [0.0, 0.0, 1.0]),
+            pivot: [0.0, 0.0, 0.0],
+        };
+
+        let (pos, _) = motion.position_at(1.0);
+        assert!((pos[0] - 1.0).abs() < 1e-9, "pos = {:?}", pos);
+        assert!(pos[1].abs() < 1e-9, "pos = {:?}", pos);
+    }
+}